@@ -0,0 +1,120 @@
+use halo2_proofs::{arithmetic::Field, circuit::*, halo2curves::ff::PrimeField, plonk::*, poly::Rotation};
+
+use crate::range_check::{RangeCheckChip, RangeCheckConfig};
+
+/// Config for a conditional-swap/mux gadget: given `(a, b)` and a boolean `swap`
+/// flag, outputs `(out_left, out_right)` — `(a, b)` when `swap = 0`, `(b, a)` when
+/// `swap = 1` — and *enforces* `out_left <= out_right` by range-checking
+/// `out_right - out_left` into `0..256^NUM_BYTES`: if the prover picks the flag that
+/// leaves the larger operand on the left, the difference underflows the field and no
+/// longer fits in `NUM_BYTES` bytes, so the gate is unsatisfiable. Callers must bound
+/// `a`/`b` themselves to at most `NUM_BYTES` bytes so the difference can't wrap.
+#[derive(Clone, Debug)]
+pub struct CondSwapConfig<F: PrimeField, const NUM_BYTES: usize> {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    swap: Column<Advice>,
+    out_left: Column<Advice>,
+    out_right: Column<Advice>,
+    diff_range: RangeCheckConfig<F, NUM_BYTES>,
+    selector: Selector,
+}
+
+pub struct CondSwapChip<F: PrimeField, const NUM_BYTES: usize> {
+    config: CondSwapConfig<F, NUM_BYTES>,
+}
+
+impl<F: PrimeField, const NUM_BYTES: usize> CondSwapChip<F, NUM_BYTES> {
+    pub fn construct(config: CondSwapConfig<F, NUM_BYTES>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> CondSwapConfig<F, NUM_BYTES> {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let swap = meta.advice_column();
+        let out_left = meta.advice_column();
+        let out_right = meta.advice_column();
+        let selector = meta.selector();
+
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+        meta.enable_equality(out_left);
+        meta.enable_equality(out_right);
+
+        let diff_column = meta.advice_column();
+        let diff_range = RangeCheckChip::configure(meta, diff_column);
+
+        meta.create_gate("CondSwap Gate", |meta| {
+            let s = meta.query_selector(selector);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let swap = meta.query_advice(swap, Rotation::cur());
+            let out_left = meta.query_advice(out_left, Rotation::cur());
+            let out_right = meta.query_advice(out_right, Rotation::cur());
+            let diff = meta.query_advice(diff_range.value, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                s.clone() * swap.clone() * (swap.clone() - one),
+                s.clone() * (out_left.clone() - (a.clone() + swap.clone() * (b.clone() - a.clone()))),
+                s.clone() * (out_right.clone() - (b.clone() + swap.clone() * (a - b))),
+                s * (out_right - out_left - diff),
+            ]
+        });
+
+        CondSwapConfig {
+            a,
+            b,
+            swap,
+            out_left,
+            out_right,
+            diff_range,
+            selector,
+        }
+    }
+
+    /// Returns `(out_left, out_right)`, canonicalized so `out_left <= out_right`: a
+    /// `swap_flag` that leaves the larger operand on the left makes `out_right -
+    /// out_left` fail the range check, so the flag can't be chosen dishonestly.
+    pub fn swap(
+        &self,
+        mut layouter: impl Layouter<F>,
+        (a, b): (&AssignedCell<F, F>, &AssignedCell<F, F>),
+        swap_flag: Value<F>,
+    ) -> Result<(AssignedCell<F, F>, AssignedCell<F, F>), Error> {
+        let diff_chip = RangeCheckChip::construct(self.config.diff_range.clone());
+        diff_chip.load_byte_table(&mut layouter)?;
+
+        layouter.assign_region(
+            || "cond swap",
+            |mut region| {
+                self.config.selector.enable(&mut region, 0)?;
+
+                let a_cell = a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                let b_cell = b.copy_advice(|| "b", &mut region, self.config.b, 0)?;
+                region.assign_advice(|| "swap", self.config.swap, 0, || swap_flag)?;
+
+                let a_value = a_cell.value().copied();
+                let b_value = b_cell.value().copied();
+                let out_left = a_value
+                    .zip(b_value)
+                    .zip(swap_flag)
+                    .map(|((a, b), s)| a + s * (b - a));
+                let out_right = a_value
+                    .zip(b_value)
+                    .zip(swap_flag)
+                    .map(|((a, b), s)| b + s * (a - b));
+                let diff = out_right.zip(out_left).map(|(r, l)| r - l);
+
+                let left_cell =
+                    region.assign_advice(|| "out_left", self.config.out_left, 0, || out_left)?;
+                let right_cell =
+                    region.assign_advice(|| "out_right", self.config.out_right, 0, || out_right)?;
+                diff_chip.assign_in_region(&mut region, 0, diff)?;
+
+                Ok((left_cell, right_cell))
+            },
+        )
+    }
+}
@@ -0,0 +1,78 @@
+//! Cost and layout reporting for the factorization circuits, built on halo2's `dev`
+//! module tooling (`CircuitCost`, `CircuitGates`, and, behind the `dev-graph`
+//! feature, `CircuitLayout`/`circuit_dot_graph`). Everything here measures
+//! [`crate::under_constrained::factor_sound::FactorCircuit`], so the columns and lookups
+//! added by [`crate::range_check`] and [`crate::cond_swap`] show up in the counts; the
+//! [`crate::under_constrained::factor_nway`] circuit is not measured.
+
+use halo2_proofs::dev::{CircuitCost, CircuitGates};
+use halo2_proofs::halo2curves::bn256::{Fr, G1};
+use halo2_proofs::plonk::{Circuit, ConstraintSystem};
+
+use crate::under_constrained::factor_sound::{FactorCircuit, MulTarget};
+
+fn sample_circuit() -> FactorCircuit<Fr> {
+    FactorCircuit {
+        lhs: Fr::from(11),
+        rhs: Fr::from(13),
+        swap: Fr::from(0),
+        mul_target: MulTarget::Instance(0),
+    }
+}
+
+/// Prints the advice/fixed/instance column counts, the maximum constraint degree, an
+/// estimated proof size, and the gates for `FactorCircuit` at the given `k`.
+pub fn report_cost(k: u32) {
+    let circuit = sample_circuit();
+
+    let mut cs = ConstraintSystem::default();
+    <FactorCircuit<Fr> as Circuit<Fr>>::configure(&mut cs);
+
+    println!("k = {k}");
+    println!("advice columns: {}", cs.num_advice_columns());
+    println!("fixed columns: {}", cs.num_fixed_columns());
+    println!("instance columns: {}", cs.num_instance_columns());
+    println!("max constraint degree: {}", cs.degree());
+
+    let cost: CircuitCost<G1, _> = CircuitCost::measure(k, &circuit);
+    let proof_size: usize = cost.proof_size(1).into();
+    println!("estimated proof size: {proof_size} bytes");
+    println!("{}", CircuitGates::collect::<Fr, _>(&circuit));
+}
+
+/// Returns a Graphviz `dot` description of `FactorCircuit`'s gates and columns.
+#[cfg(feature = "dev-graph")]
+pub fn dot_graph() -> String {
+    halo2_proofs::dev::circuit_dot_graph(&sample_circuit())
+}
+
+#[cfg(feature = "dev-graph")]
+pub fn render_layout(k: u32, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    use halo2_proofs::dev::CircuitLayout;
+    use plotters::prelude::*;
+
+    let circuit = sample_circuit();
+
+    let root = BitMapBackend::new(path, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = root.titled("FactorCircuit Layout", ("sans-serif", 20))?;
+
+    CircuitLayout::default().render(k, &circuit, &root)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dumps_cost_and_gate_metrics() {
+        report_cost(9);
+    }
+
+    #[cfg(feature = "dev-graph")]
+    #[test]
+    fn dot_graph_renders_a_graphviz_digraph() {
+        assert!(dot_graph().contains("digraph"));
+    }
+}
@@ -0,0 +1,70 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, VirtualCells},
+    poly::Rotation,
+};
+
+/// Config for a chip that proves `is_zero_expr == 1` iff the witnessed value is zero.
+///
+/// `is_zero_expr` is only well-formed when the surrounding gate is also enabled via
+/// whatever `q_enable` was passed to [`IsZeroChip::configure`]; on its own it merely
+/// records the expression so other gates can fold it in.
+#[derive(Clone, Debug)]
+pub struct IsZeroConfig<F: Field> {
+    pub value_inv: Column<Advice>,
+    is_zero_expr: Expression<F>,
+}
+
+impl<F: Field> IsZeroConfig<F> {
+    pub fn expr(&self) -> Expression<F> {
+        self.is_zero_expr.clone()
+    }
+}
+
+pub struct IsZeroChip<F: Field> {
+    config: IsZeroConfig<F>,
+}
+
+impl<F: Field> IsZeroChip<F> {
+    pub fn construct(config: IsZeroConfig<F>) -> Self {
+        IsZeroChip { config }
+    }
+
+    /// Configures `value_inv` as the modular inverse of `value` (or zero when `value`
+    /// is zero), so that `is_zero_expr = 1 - value * value_inv` is `1` exactly when
+    /// `value == 0` and `0` otherwise, enforced by `q_enable * value * is_zero_expr == 0`.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        q_enable: impl FnOnce(&mut VirtualCells<'_, F>) -> Expression<F> + Copy,
+        value: impl FnOnce(&mut VirtualCells<'_, F>) -> Expression<F>,
+        value_inv: Column<Advice>,
+    ) -> IsZeroConfig<F> {
+        let mut is_zero_expr = Expression::Constant(F::ZERO);
+
+        meta.create_gate("is_zero", |meta| {
+            let q_enable = q_enable(meta);
+            let value = value(meta);
+            let value_inv = meta.query_advice(value_inv, Rotation::cur());
+
+            is_zero_expr = Expression::Constant(F::ONE) - value.clone() * value_inv;
+            vec![q_enable * value * is_zero_expr.clone()]
+        });
+
+        IsZeroConfig {
+            value_inv,
+            is_zero_expr,
+        }
+    }
+
+    pub fn assign(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        value: Value<F>,
+    ) -> Result<(), Error> {
+        let value_inv = value.map(|value| value.invert().unwrap_or(F::ZERO));
+        region.assign_advice(|| "value inv", self.config.value_inv, offset, || value_inv)?;
+        Ok(())
+    }
+}
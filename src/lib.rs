@@ -0,0 +1,6 @@
+pub mod cond_swap;
+pub mod dev_tools;
+pub mod is_zero;
+pub mod range_check;
+pub mod under_constrained;
+pub mod utilities;
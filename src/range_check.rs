@@ -0,0 +1,278 @@
+//! A reusable byte-decomposition range-check chip, analogous to [`crate::is_zero`].
+//!
+//! `l * r - m = 0` only holds modulo the field prime, so a prover can satisfy it with
+//! `lhs`/`rhs` whose *integer* product wraps around to `mul` via some multiple of the
+//! field order. Bounding `lhs`, `rhs` and `mul` with [`RangeCheckChip`] so that the
+//! integer product stays far below the field order makes that wraparound unreachable.
+
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Region, Value},
+    halo2curves::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector, TableColumn},
+    poly::Rotation,
+};
+
+const BYTE_BITS: usize = 8;
+
+/// Range-checks a value by decomposing it into `NUM_BYTES` little-endian byte limbs,
+/// each constrained into `0..256` via a lookup, and constraining `value` to equal the
+/// limbs recomposed as a base-256 number.
+#[derive(Clone, Debug)]
+pub struct RangeCheckConfig<F: PrimeField, const NUM_BYTES: usize> {
+    pub value: Column<Advice>,
+    pub limbs: [Column<Advice>; NUM_BYTES],
+    byte_table: TableColumn,
+    selector: Selector,
+    _marker: PhantomData<F>,
+}
+
+pub struct RangeCheckChip<F: PrimeField, const NUM_BYTES: usize> {
+    config: RangeCheckConfig<F, NUM_BYTES>,
+}
+
+impl<F: PrimeField, const NUM_BYTES: usize> RangeCheckChip<F, NUM_BYTES> {
+    pub fn construct(config: RangeCheckConfig<F, NUM_BYTES>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+    ) -> RangeCheckConfig<F, NUM_BYTES> {
+        let limbs = [(); NUM_BYTES].map(|_| meta.advice_column());
+        let byte_table = meta.lookup_table_column();
+        let selector = meta.complex_selector();
+
+        meta.enable_equality(value);
+
+        meta.create_gate("decompose limbs", |meta| {
+            let s = meta.query_selector(selector);
+            let value = meta.query_advice(value, Rotation::cur());
+
+            let radix = F::from(1u64 << BYTE_BITS);
+            let mut power = F::ONE;
+            let mut composed = Expression::Constant(F::ZERO);
+            for limb in limbs {
+                composed = composed + meta.query_advice(limb, Rotation::cur()) * Expression::Constant(power);
+                power *= radix;
+            }
+
+            vec![s * (composed - value)]
+        });
+
+        for limb in limbs {
+            meta.lookup("limb is a byte", |meta| {
+                let s = meta.query_selector(selector);
+                let limb = meta.query_advice(limb, Rotation::cur());
+                vec![(s * limb, byte_table)]
+            });
+        }
+
+        RangeCheckConfig {
+            value,
+            limbs,
+            byte_table,
+            selector,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn load_byte_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "byte table",
+            |mut table| {
+                for byte in 0..(1usize << BYTE_BITS) {
+                    table.assign_cell(
+                        || "byte",
+                        self.config.byte_table,
+                        byte,
+                        || Value::known(F::from(byte as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Assigns `value` and its byte limbs at `offset` in a region the caller already
+    /// owns, so the range check can be embedded in the same row as other gates
+    /// (e.g. [`crate::cond_swap::CondSwapChip`]'s canonical-ordering check) instead
+    /// of living in its own disconnected region.
+    pub fn assign_in_region(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        value: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        self.config.selector.enable(region, offset)?;
+
+        let value_cell = region.assign_advice(|| "value", self.config.value, offset, || value)?;
+
+        for (i, limb) in self.config.limbs.iter().enumerate() {
+            let limb_value = value.map(|v| F::from(v.to_repr().as_ref()[i] as u64));
+            region.assign_advice(|| format!("limb[{i}]"), *limb, offset, || limb_value)?;
+        }
+
+        Ok(value_cell)
+    }
+
+    /// Like [`Self::assign`], but for a value the caller already loaded elsewhere
+    /// (e.g. via [`crate::utilities::UtilitiesChip::load_private`]): `copy_advice`s
+    /// `cell` into the range check's own region instead of re-witnessing it.
+    pub fn assign_copied(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "range check (copied)",
+            |mut region| {
+                self.config.selector.enable(&mut region, 0)?;
+                let value_cell = cell.copy_advice(|| "value", &mut region, self.config.value, 0)?;
+
+                let value = cell.value().copied();
+                for (i, limb) in self.config.limbs.iter().enumerate() {
+                    let limb_value = value.map(|v| F::from(v.to_repr().as_ref()[i] as u64));
+                    region.assign_advice(|| format!("limb[{i}]"), *limb, 0, || limb_value)?;
+                }
+
+                Ok(value_cell)
+            },
+        )
+    }
+
+    /// Assigns `value` and its byte limbs into a fresh region, returning the assigned
+    /// `value` cell so callers can copy-constrain it to e.g. a `FactorChip` cell.
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(|| "range check", |mut region| {
+            self.assign_in_region(&mut region, 0, value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RangeCheckChip, RangeCheckConfig};
+    use halo2_proofs::{circuit::*, dev::MockProver, halo2curves::bn256::Fr as Fp, plonk::*, poly::Rotation};
+
+    /// `lhs * rhs = mul` gated on `lhs`/`rhs` fitting in 8 bytes and `mul` in 16 bytes,
+    /// so an integer product that wraps the field can no longer satisfy it.
+    #[derive(Clone, Debug)]
+    struct WraparoundConfig {
+        lhs: Column<Advice>,
+        rhs: Column<Advice>,
+        mul: Column<Advice>,
+        selector: Selector,
+        lhs_range: RangeCheckConfig<Fp, 8>,
+        rhs_range: RangeCheckConfig<Fp, 8>,
+        mul_range: RangeCheckConfig<Fp, 16>,
+    }
+
+    #[derive(Default)]
+    struct WraparoundCircuit {
+        lhs: Value<Fp>,
+        rhs: Value<Fp>,
+    }
+
+    impl Circuit<Fp> for WraparoundCircuit {
+        type Config = WraparoundConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+            let lhs = meta.advice_column();
+            let rhs = meta.advice_column();
+            let mul = meta.advice_column();
+            let selector = meta.selector();
+
+            meta.create_gate("Mul Gate", |meta| {
+                let s = meta.query_selector(selector);
+                let l = meta.query_advice(lhs, Rotation::cur());
+                let r = meta.query_advice(rhs, Rotation::cur());
+                let m = meta.query_advice(mul, Rotation::cur());
+                vec![s * (l * r - m)]
+            });
+
+            let lhs_range = RangeCheckChip::configure(meta, lhs);
+            let rhs_range = RangeCheckChip::configure(meta, rhs);
+            let mul_range = RangeCheckChip::configure(meta, mul);
+
+            WraparoundConfig {
+                lhs,
+                rhs,
+                mul,
+                selector,
+                lhs_range,
+                rhs_range,
+                mul_range,
+            }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<Fp>) -> Result<(), Error> {
+            let lhs_chip = RangeCheckChip::construct(config.lhs_range);
+            let rhs_chip = RangeCheckChip::construct(config.rhs_range);
+            let mul_chip = RangeCheckChip::construct(config.mul_range);
+            lhs_chip.load_byte_table(&mut layouter)?;
+            rhs_chip.load_byte_table(&mut layouter)?;
+            mul_chip.load_byte_table(&mut layouter)?;
+
+            let mul_value = self.lhs.zip(self.rhs).map(|(l, r)| l * r);
+
+            // Range-check each value first, then *copy* the range-checked cells into
+            // the Mul gate's row — assigning them independently here would let a
+            // malicious prover feed an in-range value to the range check and the
+            // real out-of-range value to the Mul gate.
+            let lhs_cell = lhs_chip.assign(layouter.namespace(|| "lhs range"), self.lhs)?;
+            let rhs_cell = rhs_chip.assign(layouter.namespace(|| "rhs range"), self.rhs)?;
+            let mul_cell = mul_chip.assign(layouter.namespace(|| "mul range"), mul_value)?;
+
+            layouter.assign_region(
+                || "wraparound assign",
+                |mut region| {
+                    config.selector.enable(&mut region, 0)?;
+                    lhs_cell.copy_advice(|| "lhs", &mut region, config.lhs, 0)?;
+                    rhs_cell.copy_advice(|| "rhs", &mut region, config.rhs, 0)?;
+                    mul_cell.copy_advice(|| "mul", &mut region, config.mul, 0)?;
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn in_range_witness_is_satisfied() {
+        let k = 9;
+        let circuit = WraparoundCircuit {
+            lhs: Value::known(Fp::from(11)),
+            rhs: Value::known(Fp::from(13)),
+        };
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn wraparound_witness_is_rejected() {
+        // Previously, lhs = p - 1 (where p is the field modulus) and a matching rhs
+        // let `l * r` wrap back around to any chosen `mul` modulo p; that `lhs` no
+        // longer fits in 8 bytes, so the range check now rejects it outright.
+        let k = 9;
+        let lhs = -Fp::from(1);
+        let circuit = WraparoundCircuit {
+            lhs: Value::known(lhs),
+            rhs: Value::known(Fp::from(2)),
+        };
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod factor_nway;
+pub mod factor_sound;
+pub mod factor_underconstrained;
+pub mod prove;
@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{arithmetic::Field, circuit::*, plonk::*, poly::Rotation};
+
+use crate::is_zero::{IsZeroChip, IsZeroConfig};
+
+/// Like [`super::factor_sound::FactorConfig`], but `factor`/`acc` form a running-product
+/// chain across `N` rows instead of a single two-column row.
+#[derive(Clone, Debug)]
+pub struct FactorConfig<F: Field> {
+    factor: Column<Advice>,
+    acc: Column<Advice>,
+    instance: Column<Instance>,
+    factor_equals_one: IsZeroConfig<F>,
+    selector: Selector,
+    boundary: Selector,
+}
+#[derive(Debug, Clone)]
+struct FactorChip<F: Field> {
+    config: FactorConfig<F>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> FactorChip<F> {
+    pub fn construct(config: FactorConfig<F>) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> FactorConfig<F> {
+        let factor = meta.advice_column();
+        let acc = meta.advice_column();
+        let instance = meta.instance_column();
+        let selector = meta.selector();
+        let boundary = meta.selector();
+
+        let factor_inv = meta.advice_column();
+        let factor_equals_one = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(selector),
+            |meta| meta.query_advice(factor, Rotation::cur()) - Expression::Constant(F::ONE),
+            factor_inv,
+        );
+
+        meta.enable_equality(acc);
+        meta.enable_equality(instance);
+
+        meta.create_gate("RunningProduct & NotEqOne Gate", |meta| {
+            let s = meta.query_selector(selector);
+            let factor = meta.query_advice(factor, Rotation::cur());
+            let acc_cur = meta.query_advice(acc, Rotation::cur());
+            let acc_next = meta.query_advice(acc, Rotation::next());
+            vec![
+                s.clone() * (acc_cur * factor - acc_next),
+                s * factor_equals_one.expr(),
+            ]
+        });
+
+        meta.create_gate("acc[0] == 1", |meta| {
+            let b = meta.query_selector(boundary);
+            let acc0 = meta.query_advice(acc, Rotation::cur());
+            vec![b * (acc0 - Expression::Constant(F::ONE))]
+        });
+
+        FactorConfig {
+            factor,
+            acc,
+            instance,
+            factor_equals_one,
+            selector,
+            boundary,
+        }
+    }
+
+    /// Lays out `acc[0] = 1` (constrained via the `boundary` gate, not just witnessed),
+    /// then for each `factors[i]` enables the running-product gate
+    /// `acc[i + 1] = acc[i] * factors[i]` and the `factors[i] != 1` check, so padding
+    /// the slice with spurious `1` factors can't inflate the factor count without
+    /// being rejected.
+    pub fn assign_factors(
+        &self,
+        mut layouter: impl Layouter<F>,
+        factors: &[Value<F>],
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let factor_equals_one = IsZeroChip::construct(self.config.factor_equals_one.clone());
+
+        layouter.assign_region(
+            || "factor chain",
+            |mut region| {
+                self.config.boundary.enable(&mut region, 0)?;
+                let mut acc_cell = region.assign_advice(
+                    || "acc[0]",
+                    self.config.acc,
+                    0,
+                    || Value::known(F::ONE),
+                )?;
+
+                for (i, factor) in factors.iter().enumerate() {
+                    self.config.selector.enable(&mut region, i)?;
+                    region.assign_advice(|| "factor", self.config.factor, i, || *factor)?;
+                    factor_equals_one.assign(&mut region, i, factor.map(|f| f - F::ONE))?;
+
+                    let next_acc = acc_cell.value().copied().zip(*factor).map(|(acc, f)| acc * f);
+                    acc_cell = region.assign_advice(|| "acc", self.config.acc, i + 1, || next_acc)?;
+                }
+
+                Ok(acc_cell)
+            },
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: &AssignedCell<F, F>,
+        row: usize,
+    ) -> Result<(), Error> {
+        layouter.constrain_instance(cell.cell(), self.config.instance, row)
+    }
+}
+
+/// Proves `factors[0] * factors[1] * … * factors[N - 1] = mul` for the public `mul`
+/// instance, generalizing the two-factor [`super::factor_sound::FactorCircuit`] to an
+/// arbitrary factor count.
+#[derive(Clone, Debug)]
+pub struct FactorCircuit<F: Field, const N: usize> {
+    pub factors: [Value<F>; N],
+}
+
+impl<F: Field, const N: usize> Default for FactorCircuit<F, N> {
+    fn default() -> Self {
+        Self {
+            factors: [Value::unknown(); N],
+        }
+    }
+}
+
+impl<F: Field, const N: usize> Circuit<F> for FactorCircuit<F, N> {
+    type Config = FactorConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        FactorChip::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = FactorChip::construct(config);
+
+        let mul_cell =
+            chip.assign_factors(layouter.namespace(|| "circuit assign"), &self.factors)?;
+
+        chip.expose_public(layouter.namespace(|| "expose"), &mul_cell, 0)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FactorCircuit;
+    use halo2_proofs::circuit::Value;
+    use halo2_proofs::dev::MockProver;
+    use halo2_proofs::halo2curves::bn256::Fr as Fp;
+
+    #[test]
+    fn innocent_prover() {
+        let k = 4;
+        let factors = [11, 13, 3].map(|v| Value::known(Fp::from(v)));
+        let circuit = FactorCircuit::<Fp, 3> { factors };
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(429)]]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn padding_with_spurious_one_factor_is_rejected() {
+        let k = 4;
+        let factors = [11, 13, 1].map(|v| Value::known(Fp::from(v)));
+        let circuit = FactorCircuit::<Fp, 3> { factors };
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(143)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
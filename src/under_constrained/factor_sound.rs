@@ -1,26 +1,55 @@
 use std::marker::PhantomData;
 
-use halo2_proofs::{arithmetic::Field, circuit::*, plonk::*, poly::Rotation};
+use halo2_proofs::{arithmetic::Field, circuit::*, halo2curves::ff::PrimeField, plonk::*, poly::Rotation};
 
+use crate::cond_swap::{CondSwapChip, CondSwapConfig};
 use crate::is_zero::{IsZeroChip, IsZeroConfig};
+use crate::range_check::{RangeCheckChip, RangeCheckConfig};
+use crate::utilities::{UtilitiesChip, UtilitiesConfig, UtilitiesInstructions};
+
+/// `lhs`/`rhs` are bounded to 8 bytes (64 bits) and `mul` to 16 bytes (128 bits), far
+/// below the ~254-bit field order, so `lhs * rhs` (under 2^128) can never wrap it.
+const FACTOR_BYTES: usize = 8;
+const MUL_BYTES: usize = 16;
+
+/// Where the public `mul` value comes from: an instance column (the usual case), or
+/// a circuit-embedded constant (for "prove this specific hardcoded semiprime factors"
+/// circuits), so callers can compare the soundness of each exposure mode.
+#[derive(Clone, Copy, Debug)]
+pub enum MulTarget<F: Field> {
+    Instance(usize),
+    Constant(F),
+}
+
+impl<F: Field> Default for MulTarget<F> {
+    fn default() -> Self {
+        MulTarget::Instance(0)
+    }
+}
 
 #[derive(Clone, Debug)]
-pub struct FactorConfig<F: Field> {
+pub struct FactorConfig<F: PrimeField> {
     lhs: Column<Advice>,
     rhs: Column<Advice>,
     mul: Column<Advice>,
     instance: Column<Instance>,
     lhs_equals_one: IsZeroConfig<F>,
     rhs_equals_one: IsZeroConfig<F>,
+    cond_swap: CondSwapConfig<F, FACTOR_BYTES>,
+    lhs_range: RangeCheckConfig<F, FACTOR_BYTES>,
+    rhs_range: RangeCheckConfig<F, FACTOR_BYTES>,
+    mul_range: RangeCheckConfig<F, MUL_BYTES>,
+    utilities: UtilitiesConfig,
+    private_advice: Column<Advice>,
     selector: Selector,
 }
 #[derive(Debug, Clone)]
-struct FactorChip<F: Field> {
+struct FactorChip<F: PrimeField> {
     config: FactorConfig<F>,
     _marker: PhantomData<F>,
 }
 
-impl<F: Field> FactorChip<F> {
+impl<F: PrimeField> FactorChip<F> {
     pub fn construct(config: FactorConfig<F>) -> Self {
         Self {
             config,
@@ -51,6 +80,18 @@ impl<F: Field> FactorChip<F> {
             rhs_inv,
         );
 
+        let cond_swap = CondSwapChip::configure(meta);
+
+        let lhs_range = RangeCheckChip::configure(meta, meta.advice_column());
+        let rhs_range = RangeCheckChip::configure(meta, meta.advice_column());
+        let mul_range = RangeCheckChip::configure(meta, meta.advice_column());
+
+        let private_advice = meta.advice_column();
+        let constant = meta.fixed_column();
+        let utilities = UtilitiesChip::configure(meta, private_advice, constant);
+
+        meta.enable_equality(lhs);
+        meta.enable_equality(rhs);
         meta.enable_equality(mul);
         meta.enable_equality(instance);
 
@@ -73,16 +114,60 @@ impl<F: Field> FactorChip<F> {
             instance,
             lhs_equals_one,
             rhs_equals_one,
+            cond_swap,
+            lhs_range,
+            rhs_range,
+            mul_range,
+            utilities,
+            private_advice,
             selector,
         }
     }
 
+    /// Loads raw `lhs`/`rhs` witnesses through [`UtilitiesChip::load_private`], range-checks
+    /// the loaded cells, canonicalizes them to `(min, max)` via [`CondSwapChip`] (which
+    /// itself enforces `min <= max`, so a dishonest `swap` can't pass), range-checks their
+    /// product, then copies all three range-checked cells into the Mul gate's row — binding
+    /// the bounded values actually used in `l * r - m` instead of re-witnessing them.
     pub fn assign_row(
         &self,
         mut layouter: impl Layouter<F>,
         lhs: F,
         rhs: F,
+        swap: F,
     ) -> Result<AssignedCell<F, F>, Error> {
+        let lhs_range = RangeCheckChip::construct(self.config.lhs_range.clone());
+        let rhs_range = RangeCheckChip::construct(self.config.rhs_range.clone());
+        let mul_range = RangeCheckChip::construct(self.config.mul_range.clone());
+        lhs_range.load_byte_table(&mut layouter)?;
+        rhs_range.load_byte_table(&mut layouter)?;
+        mul_range.load_byte_table(&mut layouter)?;
+
+        let utilities = UtilitiesChip::construct(self.config.utilities.clone());
+        let lhs_private = utilities.load_private(
+            layouter.namespace(|| "load lhs"),
+            self.config.private_advice,
+            Value::known(lhs),
+        )?;
+        let rhs_private = utilities.load_private(
+            layouter.namespace(|| "load rhs"),
+            self.config.private_advice,
+            Value::known(rhs),
+        )?;
+
+        let lhs_rc = lhs_range.assign_copied(layouter.namespace(|| "lhs range"), &lhs_private)?;
+        let rhs_rc = rhs_range.assign_copied(layouter.namespace(|| "rhs range"), &rhs_private)?;
+
+        let cond_swap = CondSwapChip::construct(self.config.cond_swap.clone());
+        let (min_cell, max_cell) = cond_swap.swap(
+            layouter.namespace(|| "canonicalize factor order"),
+            (&lhs_rc, &rhs_rc),
+            Value::known(swap),
+        )?;
+
+        let mul_value = min_cell.value().copied() * max_cell.value().copied();
+        let mul_rc = mul_range.assign(layouter.namespace(|| "mul range"), mul_value)?;
+
         let lhs_equals_one = IsZeroChip::construct(self.config.lhs_equals_one.clone());
         let rhs_equals_one = IsZeroChip::construct(self.config.rhs_equals_one.clone());
 
@@ -91,18 +176,11 @@ impl<F: Field> FactorChip<F> {
             |mut region| {
                 self.config.selector.enable(&mut region, 0)?;
 
-                let lhs_cell =
-                    region.assign_advice(|| "lhs", self.config.lhs, 0, || Value::known(lhs))?;
-                let rhs_cell =
-                    region.assign_advice(|| "rhs", self.config.rhs, 0, || Value::known(rhs))?;
-                let mul_cell = region.assign_advice(
-                    || "mul",
-                    self.config.mul,
-                    0,
-                    || lhs_cell.value().copied() * rhs_cell.value(),
-                )?;
-                lhs_equals_one.assign(&mut region, 0, Value::known(lhs - F::ONE))?;
-                rhs_equals_one.assign(&mut region, 0, Value::known(rhs - F::ONE))?;
+                let lhs_cell = min_cell.copy_advice(|| "lhs", &mut region, self.config.lhs, 0)?;
+                let rhs_cell = max_cell.copy_advice(|| "rhs", &mut region, self.config.rhs, 0)?;
+                let mul_cell = mul_rc.copy_advice(|| "mul", &mut region, self.config.mul, 0)?;
+                lhs_equals_one.assign(&mut region, 0, lhs_cell.value().copied() - Value::known(F::ONE))?;
+                rhs_equals_one.assign(&mut region, 0, rhs_cell.value().copied() - Value::known(F::ONE))?;
 
                 Ok(mul_cell)
             },
@@ -117,15 +195,39 @@ impl<F: Field> FactorChip<F> {
     ) -> Result<(), Error> {
         layouter.constrain_instance(cell.cell(), self.config.instance, row)
     }
+
+    /// Binds `mul_cell` to the circuit-embedded constant `target` via `load_constant`,
+    /// instead of exposing it through the instance column.
+    pub fn bind_mul_target(
+        &self,
+        mut layouter: impl Layouter<F>,
+        mul_cell: &AssignedCell<F, F>,
+        target: F,
+    ) -> Result<(), Error> {
+        let utilities = UtilitiesChip::construct(self.config.utilities.clone());
+        let constant_cell = utilities.load_constant(
+            layouter.namespace(|| "load mul target constant"),
+            target,
+        )?;
+
+        layouter.assign_region(
+            || "bind mul to constant target",
+            |mut region| region.constrain_equal(mul_cell.cell(), constant_cell.cell()),
+        )
+    }
 }
 
 #[derive(Default)]
-pub struct FactorCircuit<F: Field> {
+pub struct FactorCircuit<F: PrimeField> {
     pub lhs: F,
     pub rhs: F,
+    /// `0` keeps `(lhs, rhs)` as given, `1` swaps them before multiplying. Only the
+    /// value that puts the smaller factor on the left is accepted: see [`CondSwapChip`].
+    pub swap: F,
+    pub mul_target: MulTarget<F>,
 }
 
-impl<F: Field> Circuit<F> for FactorCircuit<F> {
+impl<F: PrimeField> Circuit<F> for FactorCircuit<F> {
     type Config = FactorConfig<F>;
     type FloorPlanner = SimpleFloorPlanner;
 
@@ -144,10 +246,21 @@ impl<F: Field> Circuit<F> for FactorCircuit<F> {
     ) -> Result<(), Error> {
         let chip = FactorChip::construct(config);
 
-        let mul_cell =
-            chip.assign_row(layouter.namespace(|| "circuit assign"), self.lhs, self.rhs)?;
+        let mul_cell = chip.assign_row(
+            layouter.namespace(|| "circuit assign"),
+            self.lhs,
+            self.rhs,
+            self.swap,
+        )?;
 
-        chip.expose_public(layouter.namespace(|| "expose"), &mul_cell, 0)?;
+        match self.mul_target {
+            MulTarget::Instance(row) => {
+                chip.expose_public(layouter.namespace(|| "expose"), &mul_cell, row)?
+            }
+            MulTarget::Constant(target) => {
+                chip.bind_mul_target(layouter.namespace(|| "bind constant"), &mul_cell, target)?
+            }
+        }
 
         Ok(())
     }
@@ -155,27 +268,91 @@ impl<F: Field> Circuit<F> for FactorCircuit<F> {
 
 #[cfg(test)]
 mod tests {
-    use super::FactorCircuit;
+    use super::{FactorCircuit, MulTarget};
     use halo2_proofs::dev::MockProver;
     use halo2_proofs::halo2curves::bn256::Fr as Fp;
 
+    // lhs/rhs are bounded to 8 bytes and mul to 16, per `FACTOR_BYTES`/`MUL_BYTES`;
+    // the byte lookup tables need `k >= 9` rows (2^8 = 256 table rows plus slack).
+    const K: u32 = 9;
+
     #[test]
     fn innocent_prover() {
-        let k = 4;
         let lhs = Fp::from(11);
         let rhs = Fp::from(13);
-        let circuit = FactorCircuit { lhs, rhs };
-        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(143)]]).unwrap();
+        let circuit = FactorCircuit {
+            lhs,
+            rhs,
+            swap: Fp::from(0),
+            mul_target: MulTarget::Instance(0),
+        };
+        let prover = MockProver::run(K, &circuit, vec![vec![Fp::from(143)]]).unwrap();
         prover.assert_satisfied();
     }
 
     #[test]
-    fn malicious_prover() {
-        let k = 4;
+    fn malicious_prover_is_rejected() {
         let lhs = Fp::from(1);
         let rhs = Fp::from(143);
-        let circuit = FactorCircuit { lhs, rhs };
-        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(143)]]).unwrap();
+        let circuit = FactorCircuit {
+            lhs,
+            rhs,
+            swap: Fp::from(0),
+            mul_target: MulTarget::Instance(0),
+        };
+        let prover = MockProver::run(K, &circuit, vec![vec![Fp::from(143)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn canonical_order_is_enforced_regardless_of_input_order() {
+        // Whichever operand is labeled `lhs` vs `rhs`, CondSwapChip's range-checked
+        // diff forces the canonicalized (min, max) used in the Mul gate to match.
+        let unswapped = FactorCircuit {
+            lhs: Fp::from(11),
+            rhs: Fp::from(13),
+            swap: Fp::from(0),
+            mul_target: MulTarget::Instance(0),
+        };
+        let swapped = FactorCircuit {
+            lhs: Fp::from(13),
+            rhs: Fp::from(11),
+            swap: Fp::from(1),
+            mul_target: MulTarget::Instance(0),
+        };
+        let mul = vec![Fp::from(143)];
+        MockProver::run(K, &unswapped, vec![mul.clone()])
+            .unwrap()
+            .assert_satisfied();
+        MockProver::run(K, &swapped, vec![mul])
+            .unwrap()
+            .assert_satisfied();
+    }
+
+    #[test]
+    fn dishonest_swap_flag_is_rejected() {
+        // lhs > rhs but swap = 0 tries to leave the larger operand on the left;
+        // out_right - out_left then underflows the field and fails the range check.
+        let circuit = FactorCircuit {
+            lhs: Fp::from(13),
+            rhs: Fp::from(11),
+            swap: Fp::from(0),
+            mul_target: MulTarget::Instance(0),
+        };
+        let prover = MockProver::run(K, &circuit, vec![vec![Fp::from(143)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn mul_target_can_be_a_circuit_embedded_constant() {
+        let circuit = FactorCircuit {
+            lhs: Fp::from(11),
+            rhs: Fp::from(13),
+            swap: Fp::from(0),
+            mul_target: MulTarget::Constant(Fp::from(143)),
+        };
+        // No instance column value is needed: the target is embedded in the circuit.
+        let prover = MockProver::run(K, &circuit, vec![vec![]]).unwrap();
         prover.assert_satisfied();
     }
 }
@@ -0,0 +1,99 @@
+//! Real PLONK proving/verification for [`FactorCircuit`], as opposed to the
+//! `MockProver`-only checks in `factor_underconstrained` and `factor_sound`.
+
+use halo2_proofs::{
+    circuit::Value,
+    halo2curves::bn256::{Bn256, Fr, G1Affine},
+    plonk::{create_proof, keygen_pk, keygen_vk, verify_proof},
+    poly::{
+        commitment::ParamsProver,
+        kzg::{
+            commitment::{KZGCommitmentScheme, ParamsKZG},
+            multiopen::{ProverSHPLONK, VerifierSHPLONK},
+            strategy::SingleStrategy,
+        },
+    },
+    transcript::{
+        Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer,
+    },
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::under_constrained::factor_underconstrained::FactorCircuit;
+
+const K: u32 = 4;
+// Fixed seed so `verify` can recreate the exact trusted setup `generate_proof` used,
+// without the caller having to thread `ParamsKZG` through both calls.
+const SETUP_SEED: u64 = 0xF1C7_0123;
+
+fn params() -> ParamsKZG<Bn256> {
+    ParamsKZG::<Bn256>::setup(K, StdRng::seed_from_u64(SETUP_SEED))
+}
+
+/// Runs `keygen_vk`/`keygen_pk`/`create_proof` for `FactorCircuit` over a KZG
+/// commitment scheme with a Blake2b transcript, returning the serialized proof
+/// that `lhs * rhs == mul` for the public `mul` instance.
+pub fn generate_proof(lhs: Fr, rhs: Fr) -> Vec<u8> {
+    let params = params();
+    let circuit = FactorCircuit {
+        lhs: Value::known(lhs),
+        rhs: Value::known(rhs),
+    };
+    let vk = keygen_vk(&params, &circuit).expect("keygen_vk should not fail");
+    let pk = keygen_pk(&params, vk, &circuit).expect("keygen_pk should not fail");
+
+    let mul = lhs * rhs;
+    let instances: &[&[Fr]] = &[&[mul]];
+
+    let mut transcript = Blake2bWrite::<_, G1Affine, Challenge255<_>>::init(vec![]);
+    create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<_>, _, _, _, _>(
+        &params,
+        &pk,
+        &[circuit],
+        &[instances],
+        StdRng::seed_from_u64(SETUP_SEED),
+        &mut transcript,
+    )
+    .expect("proof generation should not fail");
+    transcript.finalize()
+}
+
+/// Verifies a proof produced by [`generate_proof`] against the public `mul` value,
+/// through the real `verify_proof` pipeline rather than `MockProver`.
+pub fn verify(proof: &[u8], mul: Fr) -> bool {
+    let params = params();
+    let vk = keygen_vk(&params, &FactorCircuit::default()).expect("keygen_vk should not fail");
+
+    let instances: &[&[Fr]] = &[&[mul]];
+    let mut transcript = Blake2bRead::<_, G1Affine, Challenge255<_>>::init(proof);
+    verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<_>, _, _, _>(
+        &params,
+        &vk,
+        SingleStrategy::new(&params),
+        &[instances],
+        &mut transcript,
+    )
+    .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn innocent_proof_is_accepted() {
+        let lhs = Fr::from(11);
+        let rhs = Fr::from(13);
+        let proof = generate_proof(lhs, rhs);
+        assert!(verify(&proof, lhs * rhs));
+    }
+
+    #[test]
+    fn malicious_proof_is_accepted_by_the_real_verifier() {
+        // lhs = 1 is the same "factorization" MockProver already accepts; this shows
+        // the real prover/verifier pipeline is just as unsound, not only MockProver.
+        let mul = Fr::from(143);
+        let proof = generate_proof(Fr::from(1), mul);
+        assert!(verify(&proof, mul));
+    }
+}
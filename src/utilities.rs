@@ -0,0 +1,81 @@
+//! Shared `load_private`/`load_constant` boilerplate, modeled on the `UtilitiesInstructions`
+//! trait from orchard's `circuit::gadget::utilities` and the halo2 book's simple-example chip.
+
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Column, ConstraintSystem, Error, Fixed},
+};
+
+pub trait UtilitiesInstructions<F: Field> {
+    /// A variable in the circuit, held as an assigned cell so it can be copy-constrained.
+    type Var: Clone + std::fmt::Debug;
+
+    fn load_private(
+        &self,
+        layouter: impl Layouter<F>,
+        column: Column<halo2_proofs::plonk::Advice>,
+        value: Value<F>,
+    ) -> Result<Self::Var, Error>;
+
+    fn load_constant(&self, layouter: impl Layouter<F>, constant: F) -> Result<Self::Var, Error>;
+}
+
+#[derive(Clone, Debug)]
+pub struct UtilitiesConfig {
+    advice: Column<halo2_proofs::plonk::Advice>,
+    constant: Column<Fixed>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UtilitiesChip<F: Field> {
+    config: UtilitiesConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> UtilitiesChip<F> {
+    pub fn construct(config: UtilitiesConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: Column<halo2_proofs::plonk::Advice>,
+        constant: Column<Fixed>,
+    ) -> UtilitiesConfig {
+        meta.enable_equality(advice);
+        meta.enable_constant(constant);
+
+        UtilitiesConfig { advice, constant }
+    }
+}
+
+impl<F: Field> UtilitiesInstructions<F> for UtilitiesChip<F> {
+    type Var = AssignedCell<F, F>;
+
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        column: Column<halo2_proofs::plonk::Advice>,
+        value: Value<F>,
+    ) -> Result<Self::Var, Error> {
+        layouter.assign_region(
+            || "load private",
+            |mut region| region.assign_advice(|| "private value", column, 0, || value),
+        )
+    }
+
+    fn load_constant(&self, mut layouter: impl Layouter<F>, constant: F) -> Result<Self::Var, Error> {
+        layouter.assign_region(
+            || "load constant",
+            |mut region| {
+                region.assign_advice_from_constant(|| "constant value", self.config.advice, 0, constant)
+            },
+        )
+    }
+}